@@ -3,35 +3,145 @@ use futures::prelude::*;
 use futures::{task::AtomicTask, Async, AsyncSink};
 use futures::sync::mpsc;
 use futures::task;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// One-shot drop notifier shared between a [`Publisher`]/[`Subscriber`] pair,
+/// fired from the relevant `Drop` impl and waking every task parked in a
+/// [`DropSignal`] built on top of it. Since a `DropSignal` can be cloned and
+/// polled from more than one task at once, a plain single-slot `AtomicTask`
+/// isn't enough: every currently-parked task is tracked so none of them are
+/// silently dropped in favor of the last one to register.
+#[derive(Debug, Default)]
+struct DropNotify {
+    fired: AtomicBool,
+    tasks: Mutex<Vec<task::Task>>,
+}
+
+impl DropNotify {
+    fn fire(&self) {
+        self.fired.store(true, Ordering::Release);
+        for parked in self.tasks.lock().unwrap().drain(..) {
+            parked.notify();
+        }
+    }
+}
+
+/// Future returned by [`Subscriber::closed`] and
+/// [`Publisher::all_subscribers_dropped`], resolving as soon as the
+/// corresponding [`DropNotify`] fires.
+#[derive(Debug, Clone)]
+pub struct DropSignal {
+    notify: Arc<DropNotify>,
+}
+
+impl Future for DropSignal {
+    type Item = ();
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        if self.notify.fired.load(Ordering::Acquire) {
+            return Ok(Async::Ready(()));
+        }
+        {
+            let mut tasks = self.notify.tasks.lock().unwrap();
+            // Re-polling the same task (select!/join!, spurious wakeups, ...) is
+            // normal; only register it once so `tasks` stays bounded by the
+            // number of distinct waiters rather than the number of polls.
+            if !tasks.iter().any(task::Task::will_notify_current) {
+                tasks.push(task::current());
+            }
+        }
+        if self.notify.fired.load(Ordering::Acquire) {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+/// Controls when [`Publisher::wake_all`] actually notifies sleeping subscribers.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum WakePolicy {
+    /// Notify all sleepers after every broadcast. This is the default.
+    #[default]
+    Immediate,
+    /// Only notify sleepers once at least `n` items have been broadcast since
+    /// the last wake, amortizing task wakeups for high-throughput fan-out.
+    TillReach(usize),
+}
 
 #[derive(Debug)]
 pub struct Publisher<T: Send> {
     bare_publisher: BarePublisher<T>,
     waker: Waker<AtomicTask>,
+    wake_policy: WakePolicy,
+    since_last_wake: usize,
+    closed: Arc<DropNotify>,
+    all_subscribers_dropped: Arc<DropNotify>,
 }
 #[derive(Debug)]
 pub struct Subscriber<T: Send> {
     bare_subscriber: BareSubscriber<T>,
     sleeper: Sleeper<AtomicTask>,
+    publisher_closed: Arc<DropNotify>,
+    subscriber_count: Arc<AtomicUsize>,
+    all_subscribers_dropped: Arc<DropNotify>,
 }
 
 pub fn channel<T: Send>(size: usize) -> (Publisher<T>, Subscriber<T>) {
     let (bare_publisher, bare_subscriber) = bare_channel(size);
     let (waker, sleeper) = alarm(AtomicTask::new());
+    let closed = Arc::new(DropNotify::default());
+    let subscriber_count = Arc::new(AtomicUsize::new(1));
+    let all_subscribers_dropped = Arc::new(DropNotify::default());
     (
         Publisher {
             bare_publisher,
-            task: task::current(),
             waker,
+            wake_policy: WakePolicy::default(),
+            since_last_wake: 0,
+            closed: closed.clone(),
+            all_subscribers_dropped: all_subscribers_dropped.clone(),
         },
         Subscriber {
             bare_subscriber,
             sleeper,
+            publisher_closed: closed,
+            subscriber_count,
+            all_subscribers_dropped,
         },
     )
 }
 impl<T: Send> Publisher<T> {
-    fn wake_all(&self) {
+    /// Sets the [`WakePolicy`] governing how often sleeping subscribers are notified.
+    pub fn with_wake_policy(mut self, wake_policy: WakePolicy) -> Self {
+        self.wake_policy = wake_policy;
+        self
+    }
+
+    /// A future that resolves once `get_sub_count()` reaches zero, letting a
+    /// producer stop work early instead of polling for disconnection.
+    pub fn all_subscribers_dropped(&self) -> DropSignal {
+        DropSignal {
+            notify: self.all_subscribers_dropped.clone(),
+        }
+    }
+
+    fn wake_all(&mut self) {
+        match self.wake_policy {
+            WakePolicy::Immediate => self.notify_all(),
+            WakePolicy::TillReach(n) => {
+                self.since_last_wake += 1;
+                if self.since_last_wake >= n {
+                    self.since_last_wake = 0;
+                    self.notify_all();
+                }
+            }
+        }
+    }
+
+    fn notify_all(&self) {
         for sleeper in self.waker.sleepers.iter() {
             sleeper.notify();
         }
@@ -65,7 +175,8 @@ impl<T: Send> Sink for Publisher<T> {
 
 impl<T: Send> Drop for Publisher<T> {
     fn drop(&mut self) {
-        self.close().unwrap();
+        let _ = self.close();
+        self.closed.fire();
     }
 }
 
@@ -77,34 +188,68 @@ impl<T: Send> PartialEq for Publisher<T> {
 
 impl<T: Send> Eq for Publisher<T> {}
 
+/// An item yielded by a [`Subscriber`], distinguishing a normally received
+/// item from a detected lag.
+#[derive(Debug)]
+pub enum Event<T> {
+    /// A normally received item.
+    Item(Arc<T>),
+    /// The subscriber fell behind and `usize` items were overwritten before
+    /// they could be read. Delivery resumes from the oldest still-live slot.
+    Lagged(usize),
+}
+
 impl<T: Send> Stream for Subscriber<T> {
-    type Item = Arc<T>;
+    type Item = Event<T>;
     type Error = ();
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
         match self.bare_subscriber.try_recv() {
-            Ok(arc_object) => Ok(Async::Ready(Some(arc_object))),
+            Ok(arc_object) => Ok(Async::Ready(Some(Event::Item(arc_object)))),
             Err(error) => match error {
                 TryRecvError::Empty => {
                     self.sleeper.sleeper.register();
                     Ok(Async::NotReady)
                 }
+                TryRecvError::Lagged(missed) => Ok(Async::Ready(Some(Event::Lagged(missed)))),
                 TryRecvError::Disconnected => Ok(Async::Ready(None)),
             },
         }
     }
 }
 
+impl<T: Send> Subscriber<T> {
+    /// A future that resolves as soon as the last [`Publisher`] is dropped,
+    /// even if the ring still has buffered items left to drain.
+    pub fn closed(&self) -> DropSignal {
+        DropSignal {
+            notify: self.publisher_closed.clone(),
+        }
+    }
+}
+
 impl<T: Send> Clone for Subscriber<T> {
     fn clone(&self) -> Self {
         let arc_t = Arc::new(AtomicTask::new());
-        self.sleeper.sender.send(arc_t.clone());
+        let _ = self.sleeper.sender.clone().try_send(arc_t.clone());
+        self.subscriber_count.fetch_add(1, Ordering::AcqRel);
         Self {
             bare_subscriber: self.bare_subscriber.clone(),
             sleeper: Sleeper {
                 sender: self.sleeper.sender.clone(),
                 sleeper: arc_t.clone(),
             },
+            publisher_closed: self.publisher_closed.clone(),
+            subscriber_count: self.subscriber_count.clone(),
+            all_subscribers_dropped: self.all_subscribers_dropped.clone(),
+        }
+    }
+}
+
+impl<T: Send> Drop for Subscriber<T> {
+    fn drop(&mut self) {
+        if self.subscriber_count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.all_subscribers_dropped.fire();
         }
     }
 }
@@ -117,6 +262,324 @@ impl<T: Send> PartialEq for Subscriber<T> {
 
 impl<T: Send> Eq for Subscriber<T> {}
 
+type PushFn<C, T> = Box<dyn Fn(&mut C, Arc<T>) + Send>;
+
+/// A [`Subscriber`] adapter that drains up to `cap` ready items per poll into a
+/// user-supplied collection, yielding the whole collection at once instead of
+/// one [`Arc<T>`] per poll.
+pub struct BatchSubscriber<T: Send, C> {
+    subscriber: Subscriber<T>,
+    cap: usize,
+    init: Box<dyn Fn() -> C + Send>,
+    push: PushFn<C, T>,
+}
+
+impl<T: Send, C> BatchSubscriber<T, C> {
+    /// Wraps `subscriber`, batching up to `cap` items per poll with `init`
+    /// starting each batch and `push` folding received items into it.
+    pub fn new<I, P>(subscriber: Subscriber<T>, cap: usize, init: I, push: P) -> Self
+    where
+        I: Fn() -> C + Send + 'static,
+        P: Fn(&mut C, Arc<T>) + Send + 'static,
+    {
+        BatchSubscriber {
+            subscriber,
+            cap,
+            init: Box::new(init),
+            push: Box::new(push),
+        }
+    }
+}
+
+impl<T: Send, C> Stream for BatchSubscriber<T, C> {
+    type Item = C;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        let mut batch = (self.init)();
+        let mut collected = 0;
+        loop {
+            if collected >= self.cap {
+                return Ok(Async::Ready(Some(batch)));
+            }
+            match self.subscriber.bare_subscriber.try_recv() {
+                Ok(arc_object) => {
+                    (self.push)(&mut batch, arc_object);
+                    collected += 1;
+                }
+                // A lag is a legitimate gap in the stream, not an empty ring: keep draining.
+                Err(TryRecvError::Lagged(_)) => continue,
+                Err(TryRecvError::Empty) => {
+                    if collected == 0 {
+                        self.subscriber.sleeper.sleeper.register();
+                        return Ok(Async::NotReady);
+                    }
+                    return Ok(Async::Ready(Some(batch)));
+                }
+                Err(TryRecvError::Disconnected) => {
+                    if collected == 0 {
+                        return Ok(Async::Ready(None));
+                    }
+                    return Ok(Async::Ready(Some(batch)));
+                }
+            }
+        }
+    }
+}
+
+/// A back-pressured counterpart to [`Subscriber`] that reports its read
+/// progress to the owning [`BlockingPublisher`] so publishing can block
+/// instead of overwriting data it hasn't read yet.
+#[derive(Debug)]
+pub struct BlockingSubscriber<T: Send> {
+    bare_subscriber: BareSubscriber<T>,
+    sleeper: Sleeper<AtomicTask>,
+    progress: Sleeper<AtomicUsize>,
+    publisher_task: Arc<AtomicTask>,
+}
+
+/// A lossless, back-pressured counterpart to [`Publisher`]: `start_send`
+/// returns `AsyncSink::NotReady` instead of overwriting a slot that the
+/// slowest live [`BlockingSubscriber`] hasn't read yet, complementing the
+/// existing lossy broadcast channel with a true bounded one.
+#[derive(Debug)]
+pub struct BlockingPublisher<T: Send> {
+    bare_publisher: BarePublisher<T>,
+    waker: Waker<AtomicTask>,
+    read_indices: Waker<AtomicUsize>,
+    publisher_task: Arc<AtomicTask>,
+    size: usize,
+}
+
+pub fn blocking_channel<T: Send>(size: usize) -> (BlockingPublisher<T>, BlockingSubscriber<T>) {
+    let (bare_publisher, bare_subscriber) = bare_channel(size);
+    let (waker, sleeper) = alarm(AtomicTask::new());
+    let (read_indices, progress) = alarm(AtomicUsize::new(0));
+    let publisher_task = Arc::new(AtomicTask::new());
+    (
+        BlockingPublisher {
+            bare_publisher,
+            waker,
+            read_indices,
+            publisher_task: publisher_task.clone(),
+            size,
+        },
+        BlockingSubscriber {
+            bare_subscriber,
+            sleeper,
+            progress,
+            publisher_task,
+        },
+    )
+}
+
+impl<T: Send> BlockingPublisher<T> {
+    fn wake_all(&self) {
+        for sleeper in self.waker.sleepers.iter() {
+            sleeper.notify();
+        }
+    }
+
+    /// Lowest read index among all live subscribers, i.e. how far the slowest
+    /// reader has progressed. `None` once every subscriber has dropped, since a
+    /// dropped subscriber's index (tombstoned to `usize::MAX` by
+    /// [`BlockingSubscriber`]'s `Drop`) must never hold back the writer.
+    fn min_read_index(&mut self) -> Option<usize> {
+        self.read_indices.register_receivers();
+        self.read_indices
+            .sleepers
+            .iter()
+            .map(|index| index.load(Ordering::Acquire))
+            .filter(|index| *index != usize::MAX)
+            .min()
+    }
+}
+
+impl<T: Send> GetSubCount for BlockingPublisher<T> {
+    fn get_sub_count(&self) -> usize {
+        self.bare_publisher.get_sub_count()
+    }
+}
+
+impl<T: Send> Sink for BlockingPublisher<T> {
+    type SinkItem = T;
+    type SinkError = SendError<T>;
+
+    fn start_send(&mut self, item: Self::SinkItem) -> StartSend<Self::SinkItem, Self::SinkError> {
+        self.waker.register_receivers();
+        let blocked = match self.min_read_index() {
+            Some(min_read_index) => self.bare_publisher.write_index() - min_read_index >= self.size,
+            // No live subscriber left to wait on.
+            None => false,
+        };
+        if blocked {
+            self.publisher_task.register();
+            return Ok(AsyncSink::NotReady(item));
+        }
+        self.bare_publisher.broadcast(item).map(|_| {
+            self.wake_all();
+            AsyncSink::Ready
+        })
+    }
+    fn poll_complete(&mut self) -> Poll<(), Self::SinkError> {
+        Ok(Async::Ready(()))
+    }
+    fn close(&mut self) -> Poll<(), Self::SinkError> {
+        self.poll_complete()
+    }
+}
+
+impl<T: Send> Drop for BlockingPublisher<T> {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+impl<T: Send> PartialEq for BlockingPublisher<T> {
+    fn eq(&self, other: &BlockingPublisher<T>) -> bool {
+        self.bare_publisher == other.bare_publisher
+    }
+}
+
+impl<T: Send> Eq for BlockingPublisher<T> {}
+
+impl<T: Send> Stream for BlockingSubscriber<T> {
+    type Item = Event<T>;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.bare_subscriber.try_recv() {
+            Ok(arc_object) => {
+                self.progress
+                    .sleeper
+                    .store(self.bare_subscriber.read_index(), Ordering::Release);
+                self.publisher_task.notify();
+                Ok(Async::Ready(Some(Event::Item(arc_object))))
+            }
+            Err(error) => match error {
+                TryRecvError::Empty => {
+                    self.sleeper.sleeper.register();
+                    Ok(Async::NotReady)
+                }
+                // The back-pressure invariant keeps every registered reader within
+                // `size` of the writer, so a registered subscriber can never lag.
+                TryRecvError::Lagged(_) => unreachable!("a BlockingSubscriber cannot be lapped"),
+                TryRecvError::Disconnected => Ok(Async::Ready(None)),
+            },
+        }
+    }
+}
+
+impl<T: Send> Clone for BlockingSubscriber<T> {
+    fn clone(&self) -> Self {
+        let arc_t = Arc::new(AtomicTask::new());
+        let _ = self.sleeper.sender.clone().try_send(arc_t.clone());
+        let progress_index = Arc::new(AtomicUsize::new(
+            self.progress.sleeper.load(Ordering::Acquire),
+        ));
+        let _ = self.progress.sender.clone().try_send(progress_index.clone());
+        BlockingSubscriber {
+            bare_subscriber: self.bare_subscriber.clone(),
+            sleeper: Sleeper {
+                sender: self.sleeper.sender.clone(),
+                sleeper: arc_t,
+            },
+            progress: Sleeper {
+                sender: self.progress.sender.clone(),
+                sleeper: progress_index,
+            },
+            publisher_task: self.publisher_task.clone(),
+        }
+    }
+}
+
+impl<T: Send> Drop for BlockingSubscriber<T> {
+    fn drop(&mut self) {
+        // Tombstone this subscriber's progress so it can never again hold back
+        // `BlockingPublisher::min_read_index`, and nudge the publisher in case
+        // it was parked waiting specifically on this subscriber's progress.
+        self.progress
+            .sleeper
+            .store(usize::MAX, Ordering::Release);
+        self.publisher_task.notify();
+    }
+}
+
+impl<T: Send> PartialEq for BlockingSubscriber<T> {
+    fn eq(&self, other: &BlockingSubscriber<T>) -> bool {
+        self.bare_subscriber == other.bare_subscriber
+    }
+}
+
+impl<T: Send> Eq for BlockingSubscriber<T> {}
+
+/// A "watch" [`Subscriber`] variant that does not attempt to observe every
+/// published item. Instead it always jumps straight
+/// to the most recent value, skipping everything in between, and immediately
+/// observes the current latest value on creation or clone. Suits
+/// config/state-distribution use cases where only the freshest value matters.
+#[derive(Debug)]
+pub struct WatchSubscriber<T: Send> {
+    subscriber: Subscriber<T>,
+    last_delivered: Option<usize>,
+}
+
+impl<T: Send> WatchSubscriber<T> {
+    /// Wraps `subscriber`, discarding its read position so the very next poll
+    /// immediately observes the current latest value.
+    pub fn new(subscriber: Subscriber<T>) -> Self {
+        WatchSubscriber {
+            subscriber,
+            last_delivered: None,
+        }
+    }
+}
+
+impl<T: Send> Stream for WatchSubscriber<T> {
+    type Item = Arc<T>;
+    type Error = ();
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match self.subscriber.bare_subscriber.peek_latest() {
+            Ok((index, arc_object)) => {
+                if self.last_delivered == Some(index) {
+                    self.subscriber.sleeper.sleeper.register();
+                    Ok(Async::NotReady)
+                } else {
+                    self.last_delivered = Some(index);
+                    Ok(Async::Ready(Some(arc_object)))
+                }
+            }
+            Err(TryRecvError::Empty) => {
+                self.subscriber.sleeper.sleeper.register();
+                Ok(Async::NotReady)
+            }
+            Err(TryRecvError::Disconnected) => Ok(Async::Ready(None)),
+            // `peek_latest` always reads the newest slot directly, so a watcher
+            // can never be lapped by the publisher.
+            Err(TryRecvError::Lagged(_)) => unreachable!("a WatchSubscriber cannot lag"),
+        }
+    }
+}
+
+impl<T: Send> Clone for WatchSubscriber<T> {
+    fn clone(&self) -> Self {
+        WatchSubscriber {
+            subscriber: self.subscriber.clone(),
+            // A freshly cloned watcher immediately observes the current latest value.
+            last_delivered: None,
+        }
+    }
+}
+
+impl<T: Send> PartialEq for WatchSubscriber<T> {
+    fn eq(&self, other: &WatchSubscriber<T>) -> bool {
+        self.subscriber == other.subscriber
+    }
+}
+
+impl<T: Send> Eq for WatchSubscriber<T> {}
+
 /// Helper struct used by sync and async implementations to wake Tasks / Threads
 #[derive(Debug)]
 pub struct Waker<T> {
@@ -138,9 +601,9 @@ pub struct Sleeper<T> {
 
 impl<T> Waker<T> {
     /// Register all the Tasks / Threads sent for registration.
-    pub fn register_receivers(&mut self) -> impl Future<Item=()> {
-        for receiver in self.receiver.recv() {
-            self.sleepers.push(receiver);
+    pub fn register_receivers(&mut self) {
+        while let Ok(Async::Ready(Some(sleeper))) = self.receiver.poll() {
+            self.sleepers.push(sleeper);
         }
     }
 }
@@ -162,3 +625,194 @@ pub fn alarm<T>(current: T) -> (Waker<T>, Sleeper<T>) {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::lazy;
+
+    // `Waker::register_receivers` polls an `mpsc::Receiver`, which (like any
+    // futures 0.1 poll) requires running inside a task; every test below runs
+    // its body through `lazy(..).wait()` to provide one.
+
+    #[test]
+    fn publisher_subscriber_roundtrip() {
+        lazy(|| {
+            let (mut publisher, mut subscriber) = channel(4);
+            publisher.start_send(1).unwrap();
+            publisher.start_send(2).unwrap();
+            match subscriber.poll().unwrap() {
+                Async::Ready(Some(Event::Item(item))) => assert_eq!(*item, 1),
+                other => panic!("unexpected {:?}", other),
+            }
+            match subscriber.poll().unwrap() {
+                Async::Ready(Some(Event::Item(item))) => assert_eq!(*item, 2),
+                other => panic!("unexpected {:?}", other),
+            }
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn subscriber_reports_lag_as_an_event() {
+        lazy(|| {
+            let (mut publisher, mut subscriber) = channel(2);
+            for item in 0..5 {
+                publisher.start_send(item).unwrap();
+            }
+            match subscriber.poll().unwrap() {
+                Async::Ready(Some(Event::Lagged(missed))) => assert_eq!(missed, 3),
+                other => panic!("unexpected {:?}", other),
+            }
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn subscriber_empty_registers_without_panicking() {
+        lazy(|| {
+            let (_publisher, mut subscriber) = channel::<u8>(4);
+            assert!(matches!(subscriber.poll().unwrap(), Async::NotReady));
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn wake_policy_till_reach_only_notifies_periodically() {
+        lazy(|| {
+            let (publisher, _subscriber) = channel(4);
+            let mut publisher = publisher.with_wake_policy(WakePolicy::TillReach(3));
+            for item in 0..2 {
+                publisher.start_send(item).unwrap();
+            }
+            assert_eq!(publisher.since_last_wake, 2);
+            publisher.start_send(2).unwrap();
+            assert_eq!(publisher.since_last_wake, 0);
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn batch_subscriber_collects_up_to_cap() {
+        lazy(|| {
+            let (mut publisher, subscriber) = channel(8);
+            let mut batches = BatchSubscriber::new(subscriber, 2, Vec::new, |batch, item| {
+                batch.push(*item);
+            });
+            publisher.start_send(1).unwrap();
+            publisher.start_send(2).unwrap();
+            publisher.start_send(3).unwrap();
+            match batches.poll().unwrap() {
+                Async::Ready(Some(batch)) => assert_eq!(batch, vec![1, 2]),
+                other => panic!("unexpected {:?}", other),
+            }
+            match batches.poll().unwrap() {
+                Async::Ready(Some(batch)) => assert_eq!(batch, vec![3]),
+                other => panic!("unexpected {:?}", other),
+            }
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn blocking_publisher_refuses_to_overwrite_unread_data() {
+        lazy(|| {
+            let (mut publisher, subscriber) = blocking_channel(2);
+            publisher.start_send(1).unwrap();
+            publisher.start_send(2).unwrap();
+            // The subscriber hasn't read anything yet, so a 3rd send would
+            // overwrite unread data.
+            match publisher.start_send(3) {
+                Ok(AsyncSink::NotReady(3)) => {}
+                other => panic!("unexpected {:?}", other),
+            }
+            drop(subscriber);
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn blocking_subscriber_drop_unblocks_publisher() {
+        lazy(|| {
+            let (mut publisher, subscriber) = blocking_channel(2);
+            publisher.start_send(1).unwrap();
+            publisher.start_send(2).unwrap();
+            match publisher.start_send(3) {
+                Ok(AsyncSink::NotReady(3)) => {}
+                other => panic!("unexpected {:?}", other),
+            }
+            // Dropping the only (stalled) subscriber must tombstone its read
+            // index rather than permanently pinning the minimum, or this
+            // would deadlock.
+            drop(subscriber);
+            match publisher.start_send(3) {
+                Ok(AsyncSink::Ready) => {}
+                other => panic!("unexpected {:?}", other),
+            }
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn watch_subscriber_skips_to_latest_and_dedupes() {
+        lazy(|| {
+            let (mut publisher, subscriber) = channel(8);
+            let mut watch = WatchSubscriber::new(subscriber);
+            publisher.start_send(1).unwrap();
+            publisher.start_send(2).unwrap();
+            match watch.poll().unwrap() {
+                Async::Ready(Some(item)) => assert_eq!(*item, 2),
+                other => panic!("unexpected {:?}", other),
+            }
+            assert!(matches!(watch.poll().unwrap(), Async::NotReady));
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn subscriber_closed_fires_once_publisher_drops() {
+        lazy(|| {
+            let (publisher, subscriber) = channel::<u8>(4);
+            let mut closed = subscriber.closed();
+            assert_eq!(closed.poll().unwrap(), Async::NotReady);
+            drop(publisher);
+            assert_eq!(closed.poll().unwrap(), Async::Ready(()));
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+
+    #[test]
+    fn all_subscribers_dropped_fires_once_last_subscriber_drops() {
+        lazy(|| {
+            let (publisher, subscriber) = channel::<u8>(4);
+            let other = subscriber.clone();
+            let mut all_dropped = publisher.all_subscribers_dropped();
+            assert_eq!(all_dropped.poll().unwrap(), Async::NotReady);
+            drop(subscriber);
+            assert_eq!(all_dropped.poll().unwrap(), Async::NotReady);
+            drop(other);
+            assert_eq!(all_dropped.poll().unwrap(), Async::Ready(()));
+            Ok::<(), ()>(())
+        })
+        .wait()
+        .unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Returns how many subscribers are currently registered on a channel.
+pub trait GetSubCount {
+    fn get_sub_count(&self) -> usize;
+}
+
+/// Error returned by [`BareSubscriber::try_recv`] and
+/// [`BareSubscriber::peek_latest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryRecvError {
+    /// No item is currently available.
+    Empty,
+    /// The subscriber fell behind the publisher by more than the ring's
+    /// `size` and `usize` items were overwritten before being read. The
+    /// subscriber has been fast-forwarded to the oldest still-live slot, so
+    /// the next call resumes normal delivery without double-counting.
+    Lagged(usize),
+    /// The [`BarePublisher`] has been dropped and every buffered item has
+    /// already been delivered.
+    Disconnected,
+}
+
+/// Error type of the publishing methods, carrying the unsent item back.
+/// [`BarePublisher::broadcast`] always succeeds, even with no subscribers
+/// left, so it never returns this error.
+#[derive(Debug)]
+pub struct SendError<T>(pub T);
+
+#[derive(Debug)]
+struct Inner<T> {
+    buffer: Mutex<Vec<Option<Arc<T>>>>,
+    size: usize,
+    write_index: AtomicUsize,
+    publisher_alive: AtomicBool,
+    sub_count: AtomicUsize,
+}
+
+/// The write half of a bare ring-buffer channel. Broadcasting past `size`
+/// unread items overwrites the oldest slot; see [`BareSubscriber`] for how
+/// readers detect that.
+#[derive(Debug)]
+pub struct BarePublisher<T> {
+    inner: Arc<Inner<T>>,
+}
+
+/// The read half of a bare ring-buffer channel, tracking its own read index
+/// into the shared ring.
+#[derive(Debug)]
+pub struct BareSubscriber<T> {
+    inner: Arc<Inner<T>>,
+    read_index: usize,
+}
+
+/// Creates a bare ring-buffer channel of the given `size`.
+pub fn bare_channel<T>(size: usize) -> (BarePublisher<T>, BareSubscriber<T>) {
+    let inner = Arc::new(Inner {
+        buffer: Mutex::new(vec![None; size]),
+        size,
+        write_index: AtomicUsize::new(0),
+        publisher_alive: AtomicBool::new(true),
+        sub_count: AtomicUsize::new(1),
+    });
+    (
+        BarePublisher {
+            inner: inner.clone(),
+        },
+        BareSubscriber {
+            inner,
+            read_index: 0,
+        },
+    )
+}
+
+impl<T> BarePublisher<T> {
+    /// Overwrites the oldest slot with `item` and advances the write index.
+    pub fn broadcast(&self, item: T) -> Result<(), SendError<T>> {
+        let slot = self.inner.write_index.load(Ordering::Acquire) % self.inner.size;
+        self.inner.buffer.lock().unwrap()[slot] = Some(Arc::new(item));
+        self.inner.write_index.fetch_add(1, Ordering::AcqRel);
+        Ok(())
+    }
+
+    /// Number of items broadcast so far.
+    pub fn write_index(&self) -> usize {
+        self.inner.write_index.load(Ordering::Acquire)
+    }
+}
+
+impl<T> GetSubCount for BarePublisher<T> {
+    fn get_sub_count(&self) -> usize {
+        self.inner.sub_count.load(Ordering::Acquire)
+    }
+}
+
+impl<T> PartialEq for BarePublisher<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> Eq for BarePublisher<T> {}
+
+impl<T> Drop for BarePublisher<T> {
+    fn drop(&mut self) {
+        self.inner.publisher_alive.store(false, Ordering::Release);
+    }
+}
+
+impl<T> BareSubscriber<T> {
+    /// Reads the next item, fast-forwarding over (and reporting) any slots
+    /// overwritten since the last read.
+    ///
+    /// Uses wrapping arithmetic throughout so the lag count stays accurate
+    /// even if `write_index` wraps around `usize::max_value()`.
+    pub fn try_recv(&mut self) -> Result<Arc<T>, TryRecvError> {
+        let write_index = self.inner.write_index.load(Ordering::Acquire);
+        if self.read_index == write_index {
+            return if self.inner.publisher_alive.load(Ordering::Acquire) {
+                Err(TryRecvError::Empty)
+            } else {
+                Err(TryRecvError::Disconnected)
+            };
+        }
+        if write_index.wrapping_sub(self.read_index) > self.inner.size {
+            let oldest_live = write_index.wrapping_sub(self.inner.size);
+            let missed = oldest_live.wrapping_sub(self.read_index);
+            self.read_index = oldest_live;
+            return Err(TryRecvError::Lagged(missed));
+        }
+        let slot = self.read_index % self.inner.size;
+        let item = self.inner.buffer.lock().unwrap()[slot].clone();
+        self.read_index = self.read_index.wrapping_add(1);
+        item.ok_or(TryRecvError::Empty)
+    }
+
+    /// Reads the most recently broadcast item (and its index) without
+    /// advancing `try_recv`'s own read position.
+    pub fn peek_latest(&self) -> Result<(usize, Arc<T>), TryRecvError> {
+        let write_index = self.inner.write_index.load(Ordering::Acquire);
+        if write_index == 0 {
+            return if self.inner.publisher_alive.load(Ordering::Acquire) {
+                Err(TryRecvError::Empty)
+            } else {
+                Err(TryRecvError::Disconnected)
+            };
+        }
+        let latest_index = write_index.wrapping_sub(1);
+        let slot = latest_index % self.inner.size;
+        self.inner.buffer.lock().unwrap()[slot]
+            .clone()
+            .map(|item| (latest_index, item))
+            .ok_or(TryRecvError::Empty)
+    }
+
+    /// This subscriber's current read index into the ring.
+    pub fn read_index(&self) -> usize {
+        self.read_index
+    }
+}
+
+impl<T> Clone for BareSubscriber<T> {
+    fn clone(&self) -> Self {
+        self.inner.sub_count.fetch_add(1, Ordering::AcqRel);
+        BareSubscriber {
+            inner: self.inner.clone(),
+            read_index: self.read_index,
+        }
+    }
+}
+
+impl<T> Drop for BareSubscriber<T> {
+    fn drop(&mut self) {
+        self.inner.sub_count.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl<T> PartialEq for BareSubscriber<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> Eq for BareSubscriber<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recv_in_order() {
+        let (publisher, mut subscriber) = bare_channel(4);
+        publisher.broadcast(1).unwrap();
+        publisher.broadcast(2).unwrap();
+        assert_eq!(*subscriber.try_recv().unwrap(), 1);
+        assert_eq!(*subscriber.try_recv().unwrap(), 2);
+        assert_eq!(subscriber.try_recv().unwrap_err(), TryRecvError::Empty);
+    }
+
+    #[test]
+    fn lag_is_detected_and_fast_forwards() {
+        let (publisher, mut subscriber) = bare_channel(2);
+        for item in 0..5 {
+            publisher.broadcast(item).unwrap();
+        }
+        // Ring holds only the last 2 items; 3 were overwritten before being read.
+        assert_eq!(subscriber.try_recv().unwrap_err(), TryRecvError::Lagged(3));
+        assert_eq!(*subscriber.try_recv().unwrap(), 3);
+        assert_eq!(*subscriber.try_recv().unwrap(), 4);
+    }
+
+    #[test]
+    fn disconnect_after_publisher_drop() {
+        let (publisher, mut subscriber) = bare_channel(2);
+        publisher.broadcast(1).unwrap();
+        drop(publisher);
+        assert_eq!(*subscriber.try_recv().unwrap(), 1);
+        assert_eq!(
+            subscriber.try_recv().unwrap_err(),
+            TryRecvError::Disconnected
+        );
+    }
+
+    #[test]
+    fn peek_latest_does_not_advance_try_recv() {
+        let (publisher, subscriber) = bare_channel(4);
+        publisher.broadcast(1).unwrap();
+        publisher.broadcast(2).unwrap();
+        let (index, item) = subscriber.peek_latest().unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(*item, 2);
+        // A second peek still sees the same latest value.
+        let (index, item) = subscriber.peek_latest().unwrap();
+        assert_eq!(index, 1);
+        assert_eq!(*item, 2);
+    }
+
+    #[test]
+    fn sub_count_tracks_clones_and_drops() {
+        let (publisher, subscriber) = bare_channel::<u8>(4);
+        assert_eq!(publisher.get_sub_count(), 1);
+        let clone = subscriber.clone();
+        assert_eq!(publisher.get_sub_count(), 2);
+        drop(clone);
+        assert_eq!(publisher.get_sub_count(), 1);
+    }
+}
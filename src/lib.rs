@@ -0,0 +1,13 @@
+//! A fixed-size ring-buffer broadcast channel: a slow [`BareSubscriber`] that
+//! falls behind observes a [`TryRecvError::Lagged`] instead of silently
+//! reading stale or skipped data. The [`async_`] module layers futures 0.1
+//! `Sink`/`Stream` ergonomics, plus back-pressured and latest-value variants,
+//! on top of these bare primitives.
+
+pub use std::sync::Arc;
+
+mod bare;
+pub use bare::*;
+
+pub mod async_;
+pub use async_::*;